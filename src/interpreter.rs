@@ -1,6 +1,19 @@
+mod debugger;
+mod instruction;
 mod instructions;
+mod quirks;
+mod rng;
+mod state;
 
-use crate::nibbles::{combine_three_nibbles, combine_two_nibbles, get_first_nibble, get_second_nibble};
+use std::{collections::HashSet, fs, io, path::Path};
+
+use crate::nibbles::{get_first_nibble, get_second_nibble};
+
+pub use debugger::CycleOutcome;
+pub use instruction::Instruction;
+pub use quirks::Quirks;
+use rng::Rng;
+pub use state::{Chip8State, DecodeError};
 
 pub struct Chip8 {
     memory: [u8; 4096],
@@ -46,16 +59,76 @@ pub struct Chip8 {
     /// ╚═══╩═══╩═══╩═══╝
     /// ```
     keypad: [[bool; 4]; 4],
+
+    /// Number of instructions [`Self::run_frame`] executes per simulated 60 Hz frame.
+    instructions_per_frame: u32,
+
+    /// Random source backing the `CXNN` opcode.
+    rng: Rng,
+
+    /// Which of the ambiguous opcode behaviors this VM follows.
+    quirks: Quirks,
+
+    /// Addresses that [`Self::cycle`] pauses on instead of executing.
+    breakpoints: HashSet<u16>,
 }
 
 impl Chip8 {
     /// Offset is commonly done because of old standards.
     /// Most programs written for Chip8 expect programs to start here.
-    pub const PROGRAM_MEMORY_OFFSET: u16 = 200;
+    pub const PROGRAM_MEMORY_OFFSET: u16 = 0x200;
+
+    /// Offset the font set is copied to by [`Self::new`].
+    /// `FX29` points `address_register` somewhere in this range.
+    pub const FONT_MEMORY_OFFSET: u16 = 0x00;
+
+    /// Default number of instructions executed per simulated 60 Hz frame.
+    /// This is a common choice for CHIP-8 interpreters and "feels right" for most ROMs.
+    pub const DEFAULT_INSTRUCTIONS_PER_FRAME: u32 = 11;
+
+    /// The standard CHIP-8 hexadecimal sprite font, four pixels wide, one byte per row, five bytes per glyph,
+    /// in order `0`..=`F`. Every ROM assumes this lives somewhere in low memory and that
+    /// `FX29` can find it.
+    #[rustfmt::skip]
+    pub const FONT_SET: [u8; 80] = [
+        0xF0, 0x90, 0x90, 0x90, 0xF0, // 0
+        0x20, 0x60, 0x20, 0x20, 0x70, // 1
+        0xF0, 0x10, 0xF0, 0x80, 0xF0, // 2
+        0xF0, 0x10, 0xF0, 0x10, 0xF0, // 3
+        0x90, 0x90, 0xF0, 0x10, 0x10, // 4
+        0xF0, 0x80, 0xF0, 0x10, 0xF0, // 5
+        0xF0, 0x80, 0xF0, 0x90, 0xF0, // 6
+        0xF0, 0x10, 0x20, 0x40, 0x40, // 7
+        0xF0, 0x90, 0xF0, 0x90, 0xF0, // 8
+        0xF0, 0x90, 0xF0, 0x10, 0xF0, // 9
+        0xF0, 0x90, 0xF0, 0x90, 0x90, // A
+        0xE0, 0x90, 0xE0, 0x90, 0xE0, // B
+        0xF0, 0x80, 0x80, 0x80, 0xF0, // C
+        0xE0, 0x90, 0x90, 0x90, 0xE0, // D
+        0xF0, 0x80, 0xF0, 0x80, 0xF0, // E
+        0xF0, 0x80, 0xF0, 0x80, 0x80, // F
+    ];
+
+    /// Default seed used by [`Self::new`]. Picked arbitrarily; it has no special properties
+    /// other than being non-zero.
+    pub const DEFAULT_RNG_SEED: u64 = 0xDEAD_BEEF_CAFE_F00D;
 
     pub fn new() -> Chip8 {
+        Self::with_seed(Self::DEFAULT_RNG_SEED)
+    }
+
+    /// Like [`Self::new`], but seeds the `CXNN` random source explicitly.
+    ///
+    /// Running the same ROM twice with the same seed reproduces the exact same sequence of
+    /// `CXNN` results.
+    pub fn with_seed(seed: u64) -> Chip8 {
+        let mut memory = [0; 4096];
+
+        let font_start = Self::FONT_MEMORY_OFFSET as usize;
+        memory[font_start..font_start + Self::FONT_SET.len()].copy_from_slice(&Self::FONT_SET);
+
         Self {
-            memory: [0; 4096],
+            memory,
             program_counter: Self::PROGRAM_MEMORY_OFFSET,
             address_register: 0,
             variable_register: [0; 16],
@@ -65,18 +138,117 @@ impl Chip8 {
             sound_timer: 0,
             display: [[false; 64]; 32],
             keypad: [[false; 4]; 4],
+            instructions_per_frame: Self::DEFAULT_INSTRUCTIONS_PER_FRAME,
+            rng: Rng::new(seed),
+            quirks: Quirks::default(),
+            breakpoints: HashSet::new(),
+        }
+    }
+
+    /// Captures the current memory, registers, stack, timers, display, keypad, and RNG state
+    /// as a [`Chip8State`] that can be stashed and later restored with [`Self::restore`].
+    pub fn snapshot(&self) -> Chip8State {
+        Chip8State::capture(self)
+    }
+
+    /// Restores memory, registers, stack, timers, display, keypad, and RNG state from a
+    /// snapshot previously taken with [`Self::snapshot`].
+    pub fn restore(&mut self, state: &Chip8State) {
+        state.apply_to(self);
+    }
+
+    /// Sets which of the ambiguous opcode behaviors this VM follows.
+    /// Defaults to [`Quirks::COSMAC_VIP`]; flip individual flags (or use [`Quirks::CHIP_48`])
+    /// for ROMs that expect newer interpreter behavior.
+    pub fn set_quirks(&mut self, quirks: Quirks) {
+        self.quirks = quirks;
+    }
+
+    /// Reads a ROM file from disk and loads it into memory starting at [`Self::PROGRAM_MEMORY_OFFSET`].
+    pub fn load_rom<P: AsRef<Path>>(&mut self, path: P) -> io::Result<()> {
+        let rom = fs::read(path)?;
+
+        self.load_rom_bytes(&rom)
+    }
+
+    /// Copies `rom` into memory starting at [`Self::PROGRAM_MEMORY_OFFSET`].
+    ///
+    /// Errors instead of panicking if `rom` is too large to fit in the remaining memory.
+    pub fn load_rom_bytes(&mut self, rom: &[u8]) -> io::Result<()> {
+        let start = Self::PROGRAM_MEMORY_OFFSET as usize;
+        let end = start + rom.len();
+
+        if end > self.memory.len() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "rom is {} bytes, which does not fit in the {} bytes of memory available starting at {start:#X}",
+                    rom.len(),
+                    self.memory.len() - start,
+                ),
+            ));
+        }
+
+        self.memory[start..end].copy_from_slice(rom);
+
+        Ok(())
+    }
+
+    /// Sets how many instructions [`Self::run_frame`] executes before it ticks the timers.
+    /// Higher values make ROMs feel faster without changing the fixed 60 Hz timer rate.
+    pub fn set_instructions_per_frame(&mut self, instructions_per_frame: u32) {
+        self.instructions_per_frame = instructions_per_frame;
+    }
+
+    /// Runs one [`Self::step`], unless `program_counter` is sitting on a breakpoint, in which
+    /// case execution pauses there instead.
+    pub fn cycle(&mut self) -> CycleOutcome {
+        if self.breakpoints.contains(&self.program_counter) {
+            return CycleOutcome::Breakpoint {
+                address: self.program_counter,
+            };
         }
+
+        CycleOutcome::Stepped(self.step())
     }
 
-    /// Returns an array contain the four nibbles of an opcode.
+    /// Decrements `delay_timer` and `sound_timer` toward zero.
+    ///
+    /// This should be called once per simulated 60 Hz frame, independent of how many
+    /// instructions are executed per frame.
+    pub fn tick_timers(&mut self) {
+        if self.delay_timer > 0 {
+            self.delay_timer -= 1;
+        }
+        if self.sound_timer > 0 {
+            self.sound_timer -= 1;
+        }
+    }
+
+    /// Runs [`Self::instructions_per_frame`] cycles and then ticks the timers once.
+    ///
+    /// A front end that calls this at a steady 60 Hz keeps the VM's clock accurate
+    /// regardless of how many instructions are retired per frame. Stops early, before
+    /// ticking the timers, if a breakpoint is hit.
+    pub fn run_frame(&mut self) {
+        for _ in 0..self.instructions_per_frame {
+            if let CycleOutcome::Breakpoint { .. } = self.cycle() {
+                return;
+            }
+        }
+
+        self.tick_timers();
+    }
+
+    /// Returns an array contain the four nibbles of the opcode at `address`.
     /// (a nibble is a four bit number or single hexadecimal digit)
     ///
     /// TODO: Add bounds checking
-    fn get_current_instruction(&self) -> [u8; 4] {
-        let program_counter = self.program_counter as usize;
+    fn fetch_instruction_at(&self, address: u16) -> [u8; 4] {
+        let address = address as usize;
 
-        let most_significant_byte = self.memory[program_counter];
-        let least_significant_byte = self.memory[program_counter + 1];
+        let most_significant_byte = self.memory[address];
+        let least_significant_byte = self.memory[address + 1];
 
         [
             get_first_nibble(most_significant_byte),
@@ -86,54 +258,98 @@ impl Chip8 {
         ]
     }
 
-    fn execute_current_instruction(&mut self) {
-        let nibbles = self.get_current_instruction();
-
-        let address = combine_three_nibbles(nibbles[1], nibbles[2], nibbles[3]);
-        let value = combine_two_nibbles(nibbles[2], nibbles[3]);
-        let x_register_index = nibbles[1] as usize;
-        let y_register_index = nibbles[2] as usize;
-        let sprite_height = nibbles[3];
-
-        match nibbles {
-            [0x0, _, _, _] => {},
-            [0x0, 0x0, 0xE, 0x0] => self.clear_screen(),
-            [0x0, 0x0, 0xE, 0xE] => self.return_subroutine(),
-            [0x1, _, _, _] => self.jump(address),
-            [0x2, _, _, _] => self.call_subroutine(address),
-            [0x3, _, _, _] => self.skip_if_equal_value(x_register_index, value),
-            [0x4, _, _, _] => self.skip_if_equal_value(x_register_index, value),
-            [0x5, _, _, 0x0] => self.skip_if_equal(x_register_index, y_register_index),
-            [0x6, _, _, _] => self.assign_value(x_register_index, value),
-            [0x7, _, _, _] => self.add_assign_value(x_register_index, value),
-            [0x8, _, _, 0x0] => self.assign(x_register_index, y_register_index),
-            [0x8, _, _, 0x1] => self.bitwise_or(x_register_index, y_register_index),
-            [0x8, _, _, 0x2] => self.bitwise_and(x_register_index, y_register_index),
-            [0x8, _, _, 0x3] => self.bitwise_xor(x_register_index, y_register_index),
-            [0x8, _, _, 0x4] => self.add_assign(x_register_index, y_register_index),
-            [0x8, _, _, 0x5] => self.sub_assign(x_register_index, y_register_index),
-            [0x8, _, _, 0x6] => self.right_shift_assign(x_register_index, y_register_index),
-            [0x8, _, _, 0x7] => self.sub_assign_swapped(x_register_index, y_register_index),
-            [0x8, _, _, 0xE] => self.left_shift_assign(x_register_index, y_register_index),
-            [0x9, _, _, 0x0] => self.skip_if_not_equal(x_register_index, y_register_index),
-            [0xA, _, _, _] => self.set_address_register(address),
-            [0xB, _, _, _] => self.jump_offset(address),
-            [0xC, _, _, _] => self.random_number_assign(x_register_index, value),
-            [0xD, _, _, _] => self.draw_sprite(x_register_index, y_register_index, sprite_height),
-            [0xE, _, 0x9, 0xE] => self.skip_on_key_pressed(x_register_index),
-            [0xE, _, 0xA, 0x1] => self.skip_on_key_not_pressed(x_register_index),
-            [0xF, _, 0x0, 0x7] => self.store_delay_timer(x_register_index),
-            [0xF, _, 0x0, 0xA] => self.wait_for_key_press(x_register_index),
-            [0xF, _, 0x1, 0x5] => self.set_delay_timer(x_register_index),
-            [0xF, _, 0x1, 0x8] => self.set_sound_timer(x_register_index),
-            [0xF, _, 0x1, 0xE] => self.address_register_add_assign(x_register_index),
-            [0xF, _, 0x2, 0x9] => self.set_address_register_to_character_address(x_register_index),
-            [0xF, _, 0x3, 0x3] => self.store_binary_coded_decimal_at_address_register(x_register_index),
-            [0xF, _, 0x5, 0x5] => self.store_variable_registers(x_register_index),
-            [0xF, _, 0x6, 0x5] => self.load_variable_registers(x_register_index),
-            _ => {},
+    /// Executes a single decoded [`Instruction`].
+    fn execute(&mut self, instruction: Instruction) {
+        match instruction {
+            Instruction::ClearScreen => self.clear_screen(),
+            Instruction::ReturnSubroutine => self.return_subroutine(),
+            Instruction::CallMachineCode { .. } => {}
+            Instruction::Jump { address } => self.jump(address),
+            Instruction::CallSubroutine { address } => self.call_subroutine(address),
+            Instruction::SkipIfEqualValue { register, byte } => {
+                self.skip_if_equal_value(register as usize, byte)
+            }
+            Instruction::SkipIfNotEqualValue { register, byte } => {
+                self.skip_if_not_equal_value(register as usize, byte)
+            }
+            Instruction::SkipIfEqual { x, y } => self.skip_if_equal(x as usize, y as usize),
+            Instruction::AssignValue { register, byte } => {
+                self.assign_value(register as usize, byte)
+            }
+            Instruction::AddAssignValue { register, byte } => {
+                self.add_assign_value(register as usize, byte)
+            }
+            Instruction::Assign { x, y } => self.assign(x as usize, y as usize),
+            Instruction::BitwiseOr { x, y } => self.bitwise_or(x as usize, y as usize),
+            Instruction::BitwiseAnd { x, y } => self.bitwise_and(x as usize, y as usize),
+            Instruction::BitwiseXor { x, y } => self.bitwise_xor(x as usize, y as usize),
+            Instruction::AddRegisters { x, y } => self.add_assign(x as usize, y as usize),
+            Instruction::SubAssign { x, y } => self.sub_assign(x as usize, y as usize),
+            Instruction::RightShiftAssign { x, y } => {
+                self.right_shift_assign(x as usize, y as usize)
+            }
+            Instruction::SubAssignSwapped { x, y } => {
+                self.sub_assign_swapped(x as usize, y as usize)
+            }
+            Instruction::LeftShiftAssign { x, y } => self.left_shift_assign(x as usize, y as usize),
+            Instruction::SkipIfNotEqual { x, y } => self.skip_if_not_equal(x as usize, y as usize),
+            Instruction::SetAddressRegister { address } => self.set_address_register(address),
+            Instruction::JumpOffset { address, register } => {
+                self.jump_offset(address, register as usize)
+            }
+            Instruction::RandomNumberAssign { register, byte } => {
+                self.random_number_assign(register as usize, byte)
+            }
+            Instruction::DrawSprite { x, y, height } => {
+                self.draw_sprite(x as usize, y as usize, height)
+            }
+            Instruction::SkipOnKeyPressed { register } => {
+                self.skip_on_key_pressed(register as usize)
+            }
+            Instruction::SkipOnKeyNotPressed { register } => {
+                self.skip_on_key_not_pressed(register as usize)
+            }
+            Instruction::StoreDelayTimer { register } => self.store_delay_timer(register as usize),
+            Instruction::WaitForKeyPress { register } => self.wait_for_key_press(register as usize),
+            Instruction::SetDelayTimer { register } => self.set_delay_timer(register as usize),
+            Instruction::SetSoundTimer { register } => self.set_sound_timer(register as usize),
+            Instruction::AddressRegisterAddAssign { register } => {
+                self.address_register_add_assign(register as usize)
+            }
+            Instruction::SetAddressRegisterToCharacterAddress { register } => {
+                self.set_address_register_to_character_address(register as usize)
+            }
+            Instruction::StoreBcd { register } => {
+                self.store_binary_coded_decimal_at_address_register(register as usize)
+            }
+            Instruction::StoreVariableRegisters { last_register } => {
+                self.store_variable_registers(last_register as usize)
+            }
+            Instruction::LoadVariableRegisters { last_register } => {
+                self.load_variable_registers(last_register as usize)
+            }
+            Instruction::Unknown { .. } => {}
         }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Chip8;
+
+    #[test]
+    fn load_rom_bytes_errors_instead_of_panicking_when_rom_is_too_large() {
+        let mut chip8 = Chip8::new();
+        let oversized_rom = vec![0u8; chip8.memory.len()];
+
+        assert!(chip8.load_rom_bytes(&oversized_rom).is_err());
+    }
+
+    #[test]
+    fn load_rom_bytes_accepts_a_rom_that_fits() {
+        let mut chip8 = Chip8::new();
+        let rom = vec![0u8; chip8.memory.len() - Chip8::PROGRAM_MEMORY_OFFSET as usize];
 
-        unimplemented!();
+        assert!(chip8.load_rom_bytes(&rom).is_ok());
     }
 }
@@ -0,0 +1,143 @@
+use std::ops::Range;
+
+use super::{Chip8, Instruction};
+
+/// What happened during a single [`Chip8::cycle`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CycleOutcome {
+    /// The instruction was fetched and executed normally.
+    Stepped(Instruction),
+    /// `program_counter` was sitting on a breakpoint, so nothing was executed.
+    Breakpoint { address: u16 },
+}
+
+/// Debugger-facing inspection API: disassembly, breakpoints, and single-stepping.
+impl Chip8 {
+    /// Decodes the instruction at `address` without executing it, alongside its disassembly.
+    pub fn disassemble_at(&self, address: u16) -> (Instruction, String) {
+        let instruction = Instruction::decode(self.fetch_instruction_at(address));
+        let text = instruction.to_string();
+        (instruction, text)
+    }
+
+    /// Returns the sixteen variable registers `V0..=VF`.
+    pub fn peek_registers(&self) -> &[u8; 16] {
+        &self.variable_register
+    }
+
+    /// Returns the memory in `range`, e.g. for a debugger's hex view.
+    pub fn peek_memory(&self, range: Range<usize>) -> &[u8] {
+        &self.memory[range]
+    }
+
+    /// Pauses [`Self::cycle`]/[`Self::run_frame`] whenever `program_counter` reaches `address`.
+    pub fn set_breakpoint(&mut self, address: u16) {
+        self.breakpoints.insert(address);
+    }
+
+    /// Removes a breakpoint previously set with [`Self::set_breakpoint`].
+    pub fn clear_breakpoint(&mut self, address: u16) {
+        self.breakpoints.remove(&address);
+    }
+
+    /// Returns the currently set breakpoint addresses.
+    pub fn breakpoints(&self) -> impl Iterator<Item = u16> + '_ {
+        self.breakpoints.iter().copied()
+    }
+
+    /// Fetches, advances `program_counter`, and executes a single instruction, ignoring
+    /// breakpoints, returning the instruction that ran. This is what a "step" button in a
+    /// debugger front end would call.
+    ///
+    /// `program_counter` is incremented *before* the instruction is executed so that
+    /// instructions like `jump`/`call_subroutine` can freely overwrite it.
+    pub fn step(&mut self) -> Instruction {
+        let nibbles = self.fetch_instruction_at(self.program_counter);
+
+        self.program_counter += 2;
+
+        let instruction = Instruction::decode(nibbles);
+        self.execute(instruction);
+        instruction
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Chip8, CycleOutcome, Instruction};
+
+    #[test]
+    fn disassemble_at_decodes_without_executing() {
+        let mut chip8 = Chip8::new();
+        chip8.load_rom_bytes(&[0x60, 0x05]).unwrap();
+
+        let (instruction, text) = chip8.disassemble_at(Chip8::PROGRAM_MEMORY_OFFSET);
+
+        assert_eq!(
+            instruction,
+            Instruction::AssignValue {
+                register: 0,
+                byte: 0x05
+            }
+        );
+        assert_eq!(text, "LD V0, 0x05");
+        assert_eq!(chip8.peek_registers()[0], 0);
+    }
+
+    #[test]
+    fn cycle_pauses_on_a_breakpoint_instead_of_executing() {
+        let mut chip8 = Chip8::new();
+        chip8.load_rom_bytes(&[0x60, 0x05]).unwrap();
+        chip8.set_breakpoint(Chip8::PROGRAM_MEMORY_OFFSET);
+
+        let outcome = chip8.cycle();
+
+        assert_eq!(
+            outcome,
+            CycleOutcome::Breakpoint {
+                address: Chip8::PROGRAM_MEMORY_OFFSET
+            }
+        );
+        assert_eq!(chip8.peek_registers()[0], 0);
+    }
+
+    #[test]
+    fn clear_breakpoint_lets_cycle_proceed_again() {
+        let mut chip8 = Chip8::new();
+        chip8.load_rom_bytes(&[0x60, 0x05]).unwrap();
+        chip8.set_breakpoint(Chip8::PROGRAM_MEMORY_OFFSET);
+        chip8.clear_breakpoint(Chip8::PROGRAM_MEMORY_OFFSET);
+
+        let outcome = chip8.cycle();
+
+        assert_eq!(
+            outcome,
+            CycleOutcome::Stepped(Instruction::AssignValue {
+                register: 0,
+                byte: 0x05
+            })
+        );
+        assert_eq!(chip8.peek_registers()[0], 0x05);
+    }
+
+    #[test]
+    fn breakpoints_lists_every_address_set() {
+        let mut chip8 = Chip8::new();
+        chip8.set_breakpoint(0x200);
+        chip8.set_breakpoint(0x210);
+
+        let mut addresses: Vec<u16> = chip8.breakpoints().collect();
+        addresses.sort();
+
+        assert_eq!(addresses, vec![0x200, 0x210]);
+    }
+
+    #[test]
+    fn peek_memory_reads_a_loaded_rom() {
+        let mut chip8 = Chip8::new();
+        chip8.load_rom_bytes(&[0xAB, 0xCD]).unwrap();
+
+        let start = Chip8::PROGRAM_MEMORY_OFFSET as usize;
+        assert_eq!(chip8.peek_memory(start..start + 2), [0xAB, 0xCD]);
+    }
+}
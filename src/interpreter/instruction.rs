@@ -0,0 +1,271 @@
+/// A decoded CHIP-8 opcode, ready to execute without re-inspecting nibbles.
+///
+/// Keeping decode and execute separate makes the opcode table testable in isolation
+/// and is the foundation for a disassembler and debugger.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    /// `00E0` - Clears the display.
+    ClearScreen,
+    /// `00EE` - Returns from the current subroutine.
+    ReturnSubroutine,
+    /// `0NNN` - Calls a machine-code routine. Ignored by modern interpreters.
+    CallMachineCode { address: u16 },
+    /// `1NNN` - Jumps to `address`.
+    Jump { address: u16 },
+    /// `2NNN` - Calls the subroutine at `address`.
+    CallSubroutine { address: u16 },
+    /// `3XNN` - Skips the next instruction if `VX == byte`.
+    SkipIfEqualValue { register: u8, byte: u8 },
+    /// `4XNN` - Skips the next instruction if `VX != byte`.
+    SkipIfNotEqualValue { register: u8, byte: u8 },
+    /// `5XY0` - Skips the next instruction if `VX == VY`.
+    SkipIfEqual { x: u8, y: u8 },
+    /// `6XNN` - Sets `VX` to `byte`.
+    AssignValue { register: u8, byte: u8 },
+    /// `7XNN` - Adds `byte` to `VX`, without affecting `VF`.
+    AddAssignValue { register: u8, byte: u8 },
+    /// `8XY0` - Sets `VX` to `VY`.
+    Assign { x: u8, y: u8 },
+    /// `8XY1` - Sets `VX` to `VX | VY`.
+    BitwiseOr { x: u8, y: u8 },
+    /// `8XY2` - Sets `VX` to `VX & VY`.
+    BitwiseAnd { x: u8, y: u8 },
+    /// `8XY3` - Sets `VX` to `VX ^ VY`.
+    BitwiseXor { x: u8, y: u8 },
+    /// `8XY4` - Adds `VY` to `VX`, setting `VF` to `1` on carry, else `0`.
+    AddRegisters { x: u8, y: u8 },
+    /// `8XY5` - Subtracts `VY` from `VX`, setting `VF` to `0` on borrow, else `1`.
+    SubAssign { x: u8, y: u8 },
+    /// `8XY6` - Shifts `VX` right by one. Quirk-dependent source register.
+    RightShiftAssign { x: u8, y: u8 },
+    /// `8XY7` - Sets `VX` to `VY - VX`, setting `VF` to `0` on borrow, else `1`.
+    SubAssignSwapped { x: u8, y: u8 },
+    /// `8XYE` - Shifts `VX` left by one. Quirk-dependent source register.
+    LeftShiftAssign { x: u8, y: u8 },
+    /// `9XY0` - Skips the next instruction if `VX != VY`.
+    SkipIfNotEqual { x: u8, y: u8 },
+    /// `ANNN` - Sets `address_register` to `address`.
+    SetAddressRegister { address: u16 },
+    /// `BNNN` - Jumps to `address + V0` (or `+ VX`, quirk-dependent).
+    JumpOffset { address: u16, register: u8 },
+    /// `CXNN` - Sets `VX` to `random_byte & byte`.
+    RandomNumberAssign { register: u8, byte: u8 },
+    /// `DXYN` - Draws an `N`-byte-tall sprite at `(VX, VY)`.
+    DrawSprite { x: u8, y: u8, height: u8 },
+    /// `EX9E` - Skips the next instruction if the key in `VX` is pressed.
+    SkipOnKeyPressed { register: u8 },
+    /// `EXA1` - Skips the next instruction if the key in `VX` is not pressed.
+    SkipOnKeyNotPressed { register: u8 },
+    /// `FX07` - Sets `VX` to `delay_timer`.
+    StoreDelayTimer { register: u8 },
+    /// `FX0A` - Blocks until a key is pressed, then stores it in `VX`.
+    WaitForKeyPress { register: u8 },
+    /// `FX15` - Sets `delay_timer` to `VX`.
+    SetDelayTimer { register: u8 },
+    /// `FX18` - Sets `sound_timer` to `VX`.
+    SetSoundTimer { register: u8 },
+    /// `FX1E` - Adds `VX` to `address_register`.
+    AddressRegisterAddAssign { register: u8 },
+    /// `FX29` - Sets `address_register` to the sprite address of the character in `VX`.
+    SetAddressRegisterToCharacterAddress { register: u8 },
+    /// `FX33` - Stores the binary-coded decimal representation of `VX` at `address_register`.
+    StoreBcd { register: u8 },
+    /// `FX55` - Stores `V0..=VX` to memory starting at `address_register`.
+    StoreVariableRegisters { last_register: u8 },
+    /// `FX65` - Loads `V0..=VX` from memory starting at `address_register`.
+    LoadVariableRegisters { last_register: u8 },
+    /// An opcode with no defined behavior.
+    Unknown { nibbles: [u8; 4] },
+}
+
+impl std::fmt::Display for Instruction {
+    /// Formats an [`Instruction`] the way a CHIP-8 disassembler would.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Instruction::ClearScreen => write!(f, "CLS"),
+            Instruction::ReturnSubroutine => write!(f, "RET"),
+            Instruction::CallMachineCode { address } => write!(f, "SYS {address:#05X}"),
+            Instruction::Jump { address } => write!(f, "JP {address:#05X}"),
+            Instruction::CallSubroutine { address } => write!(f, "CALL {address:#05X}"),
+            Instruction::SkipIfEqualValue { register, byte } => {
+                write!(f, "SE V{register:X}, {byte:#04X}")
+            }
+            Instruction::SkipIfNotEqualValue { register, byte } => {
+                write!(f, "SNE V{register:X}, {byte:#04X}")
+            }
+            Instruction::SkipIfEqual { x, y } => write!(f, "SE V{x:X}, V{y:X}"),
+            Instruction::AssignValue { register, byte } => {
+                write!(f, "LD V{register:X}, {byte:#04X}")
+            }
+            Instruction::AddAssignValue { register, byte } => {
+                write!(f, "ADD V{register:X}, {byte:#04X}")
+            }
+            Instruction::Assign { x, y } => write!(f, "LD V{x:X}, V{y:X}"),
+            Instruction::BitwiseOr { x, y } => write!(f, "OR V{x:X}, V{y:X}"),
+            Instruction::BitwiseAnd { x, y } => write!(f, "AND V{x:X}, V{y:X}"),
+            Instruction::BitwiseXor { x, y } => write!(f, "XOR V{x:X}, V{y:X}"),
+            Instruction::AddRegisters { x, y } => write!(f, "ADD V{x:X}, V{y:X}"),
+            Instruction::SubAssign { x, y } => write!(f, "SUB V{x:X}, V{y:X}"),
+            Instruction::RightShiftAssign { x, y } => write!(f, "SHR V{x:X}, V{y:X}"),
+            Instruction::SubAssignSwapped { x, y } => write!(f, "SUBN V{x:X}, V{y:X}"),
+            Instruction::LeftShiftAssign { x, y } => write!(f, "SHL V{x:X}, V{y:X}"),
+            Instruction::SkipIfNotEqual { x, y } => write!(f, "SNE V{x:X}, V{y:X}"),
+            Instruction::SetAddressRegister { address } => write!(f, "LD I, {address:#05X}"),
+            Instruction::JumpOffset { address, register } => {
+                write!(f, "JP V{register:X}, {address:#05X}")
+            }
+            Instruction::RandomNumberAssign { register, byte } => {
+                write!(f, "RND V{register:X}, {byte:#04X}")
+            }
+            Instruction::DrawSprite { x, y, height } => {
+                write!(f, "DRW V{x:X}, V{y:X}, {height:#03X}")
+            }
+            Instruction::SkipOnKeyPressed { register } => write!(f, "SKP V{register:X}"),
+            Instruction::SkipOnKeyNotPressed { register } => write!(f, "SKNP V{register:X}"),
+            Instruction::StoreDelayTimer { register } => write!(f, "LD V{register:X}, DT"),
+            Instruction::WaitForKeyPress { register } => write!(f, "LD V{register:X}, K"),
+            Instruction::SetDelayTimer { register } => write!(f, "LD DT, V{register:X}"),
+            Instruction::SetSoundTimer { register } => write!(f, "LD ST, V{register:X}"),
+            Instruction::AddressRegisterAddAssign { register } => write!(f, "ADD I, V{register:X}"),
+            Instruction::SetAddressRegisterToCharacterAddress { register } => {
+                write!(f, "LD F, V{register:X}")
+            }
+            Instruction::StoreBcd { register } => write!(f, "LD B, V{register:X}"),
+            Instruction::StoreVariableRegisters { last_register } => {
+                write!(f, "LD [I], V0..V{last_register:X}")
+            }
+            Instruction::LoadVariableRegisters { last_register } => {
+                write!(f, "LD V0..V{last_register:X}, [I]")
+            }
+            Instruction::Unknown { nibbles } => write!(
+                f,
+                "??? {:X}{:X}{:X}{:X}",
+                nibbles[0], nibbles[1], nibbles[2], nibbles[3]
+            ),
+        }
+    }
+}
+
+impl Instruction {
+    /// Decodes the four nibbles of a fetched opcode into an [`Instruction`].
+    pub(super) fn decode(nibbles: [u8; 4]) -> Instruction {
+        use crate::nibbles::{combine_three_nibbles, combine_two_nibbles};
+
+        let address = combine_three_nibbles(nibbles[1], nibbles[2], nibbles[3]);
+        let byte = combine_two_nibbles(nibbles[2], nibbles[3]);
+        let x = nibbles[1];
+        let y = nibbles[2];
+        let height = nibbles[3];
+
+        match nibbles {
+            [0x0, 0x0, 0xE, 0x0] => Instruction::ClearScreen,
+            [0x0, 0x0, 0xE, 0xE] => Instruction::ReturnSubroutine,
+            [0x0, _, _, _] => Instruction::CallMachineCode { address },
+            [0x1, _, _, _] => Instruction::Jump { address },
+            [0x2, _, _, _] => Instruction::CallSubroutine { address },
+            [0x3, _, _, _] => Instruction::SkipIfEqualValue { register: x, byte },
+            [0x4, _, _, _] => Instruction::SkipIfNotEqualValue { register: x, byte },
+            [0x5, _, _, 0x0] => Instruction::SkipIfEqual { x, y },
+            [0x6, _, _, _] => Instruction::AssignValue { register: x, byte },
+            [0x7, _, _, _] => Instruction::AddAssignValue { register: x, byte },
+            [0x8, _, _, 0x0] => Instruction::Assign { x, y },
+            [0x8, _, _, 0x1] => Instruction::BitwiseOr { x, y },
+            [0x8, _, _, 0x2] => Instruction::BitwiseAnd { x, y },
+            [0x8, _, _, 0x3] => Instruction::BitwiseXor { x, y },
+            [0x8, _, _, 0x4] => Instruction::AddRegisters { x, y },
+            [0x8, _, _, 0x5] => Instruction::SubAssign { x, y },
+            [0x8, _, _, 0x6] => Instruction::RightShiftAssign { x, y },
+            [0x8, _, _, 0x7] => Instruction::SubAssignSwapped { x, y },
+            [0x8, _, _, 0xE] => Instruction::LeftShiftAssign { x, y },
+            [0x9, _, _, 0x0] => Instruction::SkipIfNotEqual { x, y },
+            [0xA, _, _, _] => Instruction::SetAddressRegister { address },
+            [0xB, _, _, _] => Instruction::JumpOffset {
+                address,
+                register: x,
+            },
+            [0xC, _, _, _] => Instruction::RandomNumberAssign { register: x, byte },
+            [0xD, _, _, _] => Instruction::DrawSprite { x, y, height },
+            [0xE, _, 0x9, 0xE] => Instruction::SkipOnKeyPressed { register: x },
+            [0xE, _, 0xA, 0x1] => Instruction::SkipOnKeyNotPressed { register: x },
+            [0xF, _, 0x0, 0x7] => Instruction::StoreDelayTimer { register: x },
+            [0xF, _, 0x0, 0xA] => Instruction::WaitForKeyPress { register: x },
+            [0xF, _, 0x1, 0x5] => Instruction::SetDelayTimer { register: x },
+            [0xF, _, 0x1, 0x8] => Instruction::SetSoundTimer { register: x },
+            [0xF, _, 0x1, 0xE] => Instruction::AddressRegisterAddAssign { register: x },
+            [0xF, _, 0x2, 0x9] => Instruction::SetAddressRegisterToCharacterAddress { register: x },
+            [0xF, _, 0x3, 0x3] => Instruction::StoreBcd { register: x },
+            [0xF, _, 0x5, 0x5] => Instruction::StoreVariableRegisters { last_register: x },
+            [0xF, _, 0x6, 0x5] => Instruction::LoadVariableRegisters { last_register: x },
+            _ => Instruction::Unknown { nibbles },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Instruction;
+
+    #[test]
+    fn clear_screen_is_not_call_machine_code() {
+        assert_eq!(
+            Instruction::decode([0x0, 0x0, 0xE, 0x0]),
+            Instruction::ClearScreen
+        );
+    }
+
+    #[test]
+    fn return_subroutine_is_not_call_machine_code() {
+        assert_eq!(
+            Instruction::decode([0x0, 0x0, 0xE, 0xE]),
+            Instruction::ReturnSubroutine
+        );
+    }
+
+    #[test]
+    fn unmatched_0nnn_is_call_machine_code() {
+        assert_eq!(
+            Instruction::decode([0x0, 0x1, 0x2, 0x3]),
+            Instruction::CallMachineCode { address: 0x123 }
+        );
+    }
+
+    #[test]
+    fn skip_if_not_equal_value_is_not_skip_if_equal_value() {
+        assert_eq!(
+            Instruction::decode([0x4, 0xA, 0x1, 0x2]),
+            Instruction::SkipIfNotEqualValue {
+                register: 0xA,
+                byte: 0x12
+            }
+        );
+    }
+
+    #[test]
+    fn skip_if_equal_value_decodes_separately_from_skip_if_not_equal_value() {
+        assert_eq!(
+            Instruction::decode([0x3, 0xA, 0x1, 0x2]),
+            Instruction::SkipIfEqualValue {
+                register: 0xA,
+                byte: 0x12
+            }
+        );
+    }
+
+    #[test]
+    fn jump_decodes_address() {
+        assert_eq!(
+            Instruction::decode([0x1, 0x2, 0x3, 0x4]),
+            Instruction::Jump { address: 0x234 }
+        );
+    }
+
+    #[test]
+    fn unknown_opcode_falls_back_to_unknown() {
+        assert_eq!(
+            Instruction::decode([0x5, 0xA, 0xB, 0x1]),
+            Instruction::Unknown {
+                nibbles: [0x5, 0xA, 0xB, 0x1]
+            }
+        );
+    }
+}
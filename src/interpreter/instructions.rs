@@ -0,0 +1,248 @@
+use super::Chip8;
+
+/// Implementations of each CHIP-8 opcode, dispatched to by [`Chip8::execute`].
+impl Chip8 {
+    pub(super) fn clear_screen(&mut self) {
+        self.display = [[false; 64]; 32];
+    }
+
+    pub(super) fn return_subroutine(&mut self) {
+        self.call_stack_index -= 1;
+        self.program_counter = self.call_stack[self.call_stack_index];
+    }
+
+    pub(super) fn jump(&mut self, address: u16) {
+        self.program_counter = address;
+    }
+
+    pub(super) fn call_subroutine(&mut self, address: u16) {
+        self.call_stack[self.call_stack_index] = self.program_counter;
+        self.call_stack_index += 1;
+        self.program_counter = address;
+    }
+
+    pub(super) fn skip_if_equal_value(&mut self, x: usize, byte: u8) {
+        if self.variable_register[x] == byte {
+            self.program_counter += 2;
+        }
+    }
+
+    pub(super) fn skip_if_not_equal_value(&mut self, x: usize, byte: u8) {
+        if self.variable_register[x] != byte {
+            self.program_counter += 2;
+        }
+    }
+
+    pub(super) fn skip_if_equal(&mut self, x: usize, y: usize) {
+        if self.variable_register[x] == self.variable_register[y] {
+            self.program_counter += 2;
+        }
+    }
+
+    pub(super) fn assign_value(&mut self, x: usize, byte: u8) {
+        self.variable_register[x] = byte;
+    }
+
+    pub(super) fn add_assign_value(&mut self, x: usize, byte: u8) {
+        self.variable_register[x] = self.variable_register[x].wrapping_add(byte);
+    }
+
+    pub(super) fn assign(&mut self, x: usize, y: usize) {
+        self.variable_register[x] = self.variable_register[y];
+    }
+
+    pub(super) fn bitwise_or(&mut self, x: usize, y: usize) {
+        self.variable_register[x] |= self.variable_register[y];
+    }
+
+    pub(super) fn bitwise_and(&mut self, x: usize, y: usize) {
+        self.variable_register[x] &= self.variable_register[y];
+    }
+
+    pub(super) fn bitwise_xor(&mut self, x: usize, y: usize) {
+        self.variable_register[x] ^= self.variable_register[y];
+    }
+
+    pub(super) fn add_assign(&mut self, x: usize, y: usize) {
+        let (result, carry) = self.variable_register[x].overflowing_add(self.variable_register[y]);
+        self.variable_register[x] = result;
+        self.variable_register[0xF] = carry as u8;
+    }
+
+    pub(super) fn sub_assign(&mut self, x: usize, y: usize) {
+        let (result, borrow) = self.variable_register[x].overflowing_sub(self.variable_register[y]);
+        self.variable_register[x] = result;
+        self.variable_register[0xF] = !borrow as u8;
+    }
+
+    pub(super) fn right_shift_assign(&mut self, x: usize, y: usize) {
+        let source = if self.quirks.shift_in_place { x } else { y };
+        let shifted_out = self.variable_register[source] & 0x1;
+        self.variable_register[x] = self.variable_register[source] >> 1;
+        self.variable_register[0xF] = shifted_out;
+    }
+
+    pub(super) fn sub_assign_swapped(&mut self, x: usize, y: usize) {
+        let (result, borrow) = self.variable_register[y].overflowing_sub(self.variable_register[x]);
+        self.variable_register[x] = result;
+        self.variable_register[0xF] = !borrow as u8;
+    }
+
+    pub(super) fn left_shift_assign(&mut self, x: usize, y: usize) {
+        let source = if self.quirks.shift_in_place { x } else { y };
+        let shifted_out = (self.variable_register[source] & 0x80) >> 7;
+        self.variable_register[x] = self.variable_register[source] << 1;
+        self.variable_register[0xF] = shifted_out;
+    }
+
+    pub(super) fn skip_if_not_equal(&mut self, x: usize, y: usize) {
+        if self.variable_register[x] != self.variable_register[y] {
+            self.program_counter += 2;
+        }
+    }
+
+    pub(super) fn set_address_register(&mut self, address: u16) {
+        self.address_register = address;
+    }
+
+    pub(super) fn jump_offset(&mut self, address: u16, x: usize) {
+        let offset_register = if self.quirks.jump_offset_uses_vx {
+            x
+        } else {
+            0x0
+        };
+        self.program_counter = address + self.variable_register[offset_register] as u16;
+    }
+
+    pub(super) fn random_number_assign(&mut self, x: usize, byte: u8) {
+        self.variable_register[x] = self.rng.next_byte() & byte;
+    }
+
+    pub(super) fn draw_sprite(&mut self, x: usize, y: usize, height: u8) {
+        let origin_x = self.variable_register[x] as usize % 64;
+        let origin_y = self.variable_register[y] as usize % 32;
+
+        self.variable_register[0xF] = 0;
+
+        for row in 0..height as usize {
+            let pixel_y = origin_y + row;
+            if pixel_y >= 32 {
+                break;
+            }
+
+            let sprite_row = self.memory[self.address_register as usize + row];
+
+            for column in 0..8 {
+                let pixel_x = origin_x + column;
+                if pixel_x >= 64 {
+                    break;
+                }
+
+                let sprite_pixel = (sprite_row >> (7 - column)) & 0x1 == 1;
+                if sprite_pixel {
+                    if self.display[pixel_y][pixel_x] {
+                        self.variable_register[0xF] = 1;
+                    }
+                    self.display[pixel_y][pixel_x] ^= true;
+                }
+            }
+        }
+    }
+
+    pub(super) fn skip_on_key_pressed(&mut self, x: usize) {
+        let key = self.variable_register[x];
+        if self.is_key_pressed(key) {
+            self.program_counter += 2;
+        }
+    }
+
+    pub(super) fn skip_on_key_not_pressed(&mut self, x: usize) {
+        let key = self.variable_register[x];
+        if !self.is_key_pressed(key) {
+            self.program_counter += 2;
+        }
+    }
+
+    /// The key value at each `keypad[row][column]`, following the layout documented on `keypad`.
+    const KEYPAD_LAYOUT: [[u8; 4]; 4] = [
+        [0x1, 0x2, 0x3, 0xC],
+        [0x4, 0x5, 0x6, 0xD],
+        [0x7, 0x8, 0x9, 0xE],
+        [0xA, 0x0, 0xB, 0xF],
+    ];
+
+    fn is_key_pressed(&self, key: u8) -> bool {
+        self.keypad
+            .iter()
+            .flatten()
+            .zip(Self::KEYPAD_LAYOUT.iter().flatten())
+            .any(|(&pressed, &layout_key)| pressed && layout_key == key)
+    }
+
+    pub(super) fn store_delay_timer(&mut self, x: usize) {
+        self.variable_register[x] = self.delay_timer;
+    }
+
+    pub(super) fn wait_for_key_press(&mut self, x: usize) {
+        match (0x0..=0xF).find(|&key| self.is_key_pressed(key)) {
+            Some(key) => self.variable_register[x] = key,
+            None => self.program_counter -= 2,
+        }
+    }
+
+    pub(super) fn set_delay_timer(&mut self, x: usize) {
+        self.delay_timer = self.variable_register[x];
+    }
+
+    pub(super) fn set_sound_timer(&mut self, x: usize) {
+        self.sound_timer = self.variable_register[x];
+    }
+
+    pub(super) fn address_register_add_assign(&mut self, x: usize) {
+        let result = self.address_register as u32 + self.variable_register[x] as u32;
+        let overflow = result > 0x0FFF;
+        self.address_register = (result & 0x0FFF) as u16;
+
+        if self.quirks.index_overflow_sets_vf {
+            self.variable_register[0xF] = overflow as u8;
+        }
+    }
+
+    pub(super) fn set_address_register_to_character_address(&mut self, x: usize) {
+        let character = self.variable_register[x] as u16 & 0xF;
+        self.address_register = Self::FONT_MEMORY_OFFSET + character * 5;
+    }
+
+    pub(super) fn store_binary_coded_decimal_at_address_register(&mut self, x: usize) {
+        let value = self.variable_register[x];
+        let address = self.address_register as usize;
+
+        self.memory[address] = value / 100;
+        self.memory[address + 1] = (value / 10) % 10;
+        self.memory[address + 2] = value % 10;
+    }
+
+    pub(super) fn store_variable_registers(&mut self, last_register: usize) {
+        let address = self.address_register as usize;
+
+        for offset in 0..=last_register {
+            self.memory[address + offset] = self.variable_register[offset];
+        }
+
+        if !self.quirks.memory_ops_leave_index_unchanged {
+            self.address_register += last_register as u16 + 1;
+        }
+    }
+
+    pub(super) fn load_variable_registers(&mut self, last_register: usize) {
+        let address = self.address_register as usize;
+
+        for offset in 0..=last_register {
+            self.variable_register[offset] = self.memory[address + offset];
+        }
+
+        if !self.quirks.memory_ops_leave_index_unchanged {
+            self.address_register += last_register as u16 + 1;
+        }
+    }
+}
@@ -0,0 +1,146 @@
+/// Flags for opcode behaviors that real ROMs disagree about.
+///
+/// Different CHIP-8 interpreters over the years made different calls on a handful of
+/// ambiguous opcodes. [`Quirks::default`] matches the original COSMAC VIP interpreter;
+/// [`Quirks::CHIP_48`] matches the later CHIP-48/SUPER-CHIP behavior some ROMs expect.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Quirks {
+    /// `8XY6`/`8XYE` - if `true`, shift `VX` in place. If `false`, shift `VY` into `VX`.
+    pub shift_in_place: bool,
+
+    /// `FX55`/`FX65` - if `true`, leave `address_register` unchanged. If `false`, advance it
+    /// past the registers that were stored/loaded.
+    pub memory_ops_leave_index_unchanged: bool,
+
+    /// `BXNN` - if `true`, jump to `address + VX`. If `false`, jump to `address + V0`.
+    pub jump_offset_uses_vx: bool,
+
+    /// `FX1E` - if `true`, set `VF` to `1` when `address_register` overflows past `0xFFF`.
+    pub index_overflow_sets_vf: bool,
+}
+
+impl Default for Quirks {
+    /// The original COSMAC VIP behavior.
+    fn default() -> Self {
+        Self::COSMAC_VIP
+    }
+}
+
+impl Quirks {
+    /// The original COSMAC VIP behavior.
+    pub const COSMAC_VIP: Quirks = Quirks {
+        shift_in_place: false,
+        memory_ops_leave_index_unchanged: false,
+        jump_offset_uses_vx: false,
+        index_overflow_sets_vf: false,
+    };
+
+    /// The CHIP-48/SUPER-CHIP behavior many newer ROMs assume.
+    pub const CHIP_48: Quirks = Quirks {
+        shift_in_place: true,
+        memory_ops_leave_index_unchanged: true,
+        jump_offset_uses_vx: true,
+        index_overflow_sets_vf: false,
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::Chip8;
+    use super::Quirks;
+
+    fn chip8_running(rom: &[u8], quirks: Quirks) -> Chip8 {
+        let mut chip8 = Chip8::new();
+        chip8.set_quirks(quirks);
+        chip8.load_rom_bytes(rom).unwrap();
+        chip8
+    }
+
+    #[test]
+    fn shift_in_place_selects_vx_instead_of_vy() {
+        // LD V0,#10 ; LD V1,#03 ; SHR V0 {,V1}
+        let rom = [0x60, 0x10, 0x61, 0x03, 0x80, 0x16];
+
+        let mut cosmac_vip = chip8_running(&rom, Quirks::COSMAC_VIP);
+        for _ in 0..3 {
+            cosmac_vip.step();
+        }
+        assert_eq!(cosmac_vip.peek_registers()[0], 0x01);
+        assert_eq!(cosmac_vip.peek_registers()[0xF], 1);
+
+        let mut chip_48 = chip8_running(&rom, Quirks::CHIP_48);
+        for _ in 0..3 {
+            chip_48.step();
+        }
+        assert_eq!(chip_48.peek_registers()[0], 0x08);
+        assert_eq!(chip_48.peek_registers()[0xF], 0);
+    }
+
+    #[test]
+    fn jump_offset_uses_vx_selects_vx_instead_of_v0() {
+        // LD V0,#06 ; LD V2,#10 ; JP V2,0x200 ; [0x206] LD V5,#AA ; ... ; [0x210] LD V5,#BB
+        let mut rom = vec![0x60, 0x06, 0x62, 0x10, 0xB2, 0x00, 0x65, 0xAA];
+        rom.resize(0x10, 0x00);
+        rom.extend_from_slice(&[0x65, 0xBB]);
+
+        let mut cosmac_vip = chip8_running(&rom, Quirks::COSMAC_VIP);
+        for _ in 0..4 {
+            cosmac_vip.step();
+        }
+        assert_eq!(cosmac_vip.peek_registers()[5], 0xAA);
+
+        let mut chip_48 = chip8_running(&rom, Quirks::CHIP_48);
+        for _ in 0..4 {
+            chip_48.step();
+        }
+        assert_eq!(chip_48.peek_registers()[5], 0xBB);
+    }
+
+    #[test]
+    fn memory_ops_leave_index_unchanged_skips_the_index_advance() {
+        // LD V0,#11 ; LD I,0x300 ; LD [I],V0 ; LD V1,#22 ; LD [I],V0..V1
+        let rom = [0x60, 0x11, 0xA3, 0x00, 0xF0, 0x55, 0x61, 0x22, 0xF1, 0x55];
+
+        let mut leaves_unchanged = chip8_running(&rom, Quirks::CHIP_48);
+        for _ in 0..5 {
+            leaves_unchanged.step();
+        }
+        assert_eq!(leaves_unchanged.peek_memory(0x301..0x302)[0], 0x22);
+
+        let mut advances = chip8_running(&rom, Quirks::COSMAC_VIP);
+        for _ in 0..5 {
+            advances.step();
+        }
+        assert_eq!(advances.peek_memory(0x301..0x302)[0], 0x11);
+    }
+
+    #[test]
+    fn index_overflow_sets_vf_flags_overflow_past_0xfff() {
+        // LD V0,#05 ; LD I,0xFFE ; ADD I,V0
+        let rom = [0x60, 0x05, 0xAF, 0xFE, 0xF0, 0x1E];
+
+        let mut flags_overflow = chip8_running(
+            &rom,
+            Quirks {
+                index_overflow_sets_vf: true,
+                ..Quirks::default()
+            },
+        );
+        for _ in 0..3 {
+            flags_overflow.step();
+        }
+        assert_eq!(flags_overflow.peek_registers()[0xF], 1);
+
+        let mut ignores_overflow = chip8_running(
+            &rom,
+            Quirks {
+                index_overflow_sets_vf: false,
+                ..Quirks::default()
+            },
+        );
+        for _ in 0..3 {
+            ignores_overflow.step();
+        }
+        assert_eq!(ignores_overflow.peek_registers()[0xF], 0);
+    }
+}
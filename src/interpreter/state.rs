@@ -0,0 +1,287 @@
+use std::{error, fmt};
+
+use super::{quirks::Quirks, rng::Rng, Chip8};
+
+/// A snapshot of everything a [`Chip8`] needs to resume execution: memory, registers, stack,
+/// timers, display, keypad, and RNG state. Taking one costs a copy; restoring one is just an
+/// assignment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Chip8State {
+    memory: [u8; 4096],
+    program_counter: u16,
+    address_register: u16,
+    variable_register: [u8; 16],
+    call_stack: [u16; 16],
+    call_stack_index: u32,
+    delay_timer: u8,
+    sound_timer: u8,
+    display: [[bool; 64]; 32],
+    keypad: [[bool; 4]; 4],
+    instructions_per_frame: u32,
+    rng_state: u64,
+    quirks: Quirks,
+}
+
+/// Bumped whenever [`Chip8State::to_bytes`]'s layout changes, so a save made by an older
+/// version can be rejected instead of misread.
+const VERSION: u8 = 1;
+
+impl Chip8State {
+    pub(super) fn capture(chip8: &Chip8) -> Chip8State {
+        Chip8State {
+            memory: chip8.memory,
+            program_counter: chip8.program_counter,
+            address_register: chip8.address_register,
+            variable_register: chip8.variable_register,
+            call_stack: chip8.call_stack,
+            call_stack_index: chip8.call_stack_index as u32,
+            delay_timer: chip8.delay_timer,
+            sound_timer: chip8.sound_timer,
+            display: chip8.display,
+            keypad: chip8.keypad,
+            instructions_per_frame: chip8.instructions_per_frame,
+            rng_state: chip8.rng.state(),
+            quirks: chip8.quirks,
+        }
+    }
+
+    pub(super) fn apply_to(&self, chip8: &mut Chip8) {
+        chip8.memory = self.memory;
+        chip8.program_counter = self.program_counter;
+        chip8.address_register = self.address_register;
+        chip8.variable_register = self.variable_register;
+        chip8.call_stack = self.call_stack;
+        chip8.call_stack_index = self.call_stack_index as usize;
+        chip8.delay_timer = self.delay_timer;
+        chip8.sound_timer = self.sound_timer;
+        chip8.display = self.display;
+        chip8.keypad = self.keypad;
+        chip8.instructions_per_frame = self.instructions_per_frame;
+        chip8.rng = Rng::from_state(self.rng_state);
+        chip8.quirks = self.quirks;
+    }
+
+    /// Serializes this snapshot to a versioned byte representation.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes =
+            Vec::with_capacity(1 + 4096 + 2 + 2 + 16 + 32 + 4 + 1 + 1 + 2048 + 16 + 4 + 8 + 4);
+
+        bytes.push(VERSION);
+        bytes.extend_from_slice(&self.memory);
+        bytes.extend_from_slice(&self.program_counter.to_le_bytes());
+        bytes.extend_from_slice(&self.address_register.to_le_bytes());
+        bytes.extend_from_slice(&self.variable_register);
+        for address in self.call_stack {
+            bytes.extend_from_slice(&address.to_le_bytes());
+        }
+        bytes.extend_from_slice(&self.call_stack_index.to_le_bytes());
+        bytes.push(self.delay_timer);
+        bytes.push(self.sound_timer);
+        for row in self.display {
+            bytes.extend(row.iter().map(|&pixel| pixel as u8));
+        }
+        for row in self.keypad {
+            bytes.extend(row.iter().map(|&pressed| pressed as u8));
+        }
+        bytes.extend_from_slice(&self.instructions_per_frame.to_le_bytes());
+        bytes.extend_from_slice(&self.rng_state.to_le_bytes());
+        bytes.push(self.quirks.shift_in_place as u8);
+        bytes.push(self.quirks.memory_ops_leave_index_unchanged as u8);
+        bytes.push(self.quirks.jump_offset_uses_vx as u8);
+        bytes.push(self.quirks.index_overflow_sets_vf as u8);
+
+        bytes
+    }
+
+    /// Deserializes a snapshot previously produced by [`Self::to_bytes`].
+    pub fn from_bytes(bytes: &[u8]) -> Result<Chip8State, DecodeError> {
+        let mut reader = ByteReader::new(bytes);
+
+        let version = reader.take_u8()?;
+        if version != VERSION {
+            return Err(DecodeError::UnsupportedVersion(version));
+        }
+
+        let memory = reader.take_array()?;
+        let program_counter = u16::from_le_bytes(reader.take_array()?);
+        let address_register = u16::from_le_bytes(reader.take_array()?);
+        let variable_register = reader.take_array()?;
+        let mut call_stack = [0u16; 16];
+        for slot in &mut call_stack {
+            *slot = u16::from_le_bytes(reader.take_array()?);
+        }
+        let call_stack_index = u32::from_le_bytes(reader.take_array()?);
+        let delay_timer = reader.take_u8()?;
+        let sound_timer = reader.take_u8()?;
+        let mut display = [[false; 64]; 32];
+        for row in &mut display {
+            for pixel in row {
+                *pixel = reader.take_u8()? != 0;
+            }
+        }
+        let mut keypad = [[false; 4]; 4];
+        for row in &mut keypad {
+            for key in row {
+                *key = reader.take_u8()? != 0;
+            }
+        }
+        let instructions_per_frame = u32::from_le_bytes(reader.take_array()?);
+        let rng_state = u64::from_le_bytes(reader.take_array()?);
+        let quirks = Quirks {
+            shift_in_place: reader.take_u8()? != 0,
+            memory_ops_leave_index_unchanged: reader.take_u8()? != 0,
+            jump_offset_uses_vx: reader.take_u8()? != 0,
+            index_overflow_sets_vf: reader.take_u8()? != 0,
+        };
+
+        reader.expect_exhausted()?;
+
+        Ok(Chip8State {
+            memory,
+            program_counter,
+            address_register,
+            variable_register,
+            call_stack,
+            call_stack_index,
+            delay_timer,
+            sound_timer,
+            display,
+            keypad,
+            instructions_per_frame,
+            rng_state,
+            quirks,
+        })
+    }
+}
+
+/// A tiny cursor over a byte slice, tracking how far [`Chip8State::from_bytes`] has read.
+struct ByteReader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> ByteReader<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, position: 0 }
+    }
+
+    fn take_u8(&mut self) -> Result<u8, DecodeError> {
+        Ok(self.take_array::<1>()?[0])
+    }
+
+    fn take_array<const N: usize>(&mut self) -> Result<[u8; N], DecodeError> {
+        let end = self.position + N;
+        let slice = self
+            .bytes
+            .get(self.position..end)
+            .ok_or(DecodeError::UnexpectedEnd)?;
+        self.position = end;
+        Ok(slice
+            .try_into()
+            .expect("slice length matches N by construction"))
+    }
+
+    fn expect_exhausted(&self) -> Result<(), DecodeError> {
+        if self.position == self.bytes.len() {
+            Ok(())
+        } else {
+            Err(DecodeError::TrailingBytes {
+                extra: self.bytes.len() - self.position,
+            })
+        }
+    }
+}
+
+/// An error decoding a [`Chip8State`] from bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecodeError {
+    /// The version byte didn't match the layout this build of the crate understands.
+    UnsupportedVersion(u8),
+    /// The byte slice ended before a field could be fully read.
+    UnexpectedEnd,
+    /// The byte slice had more bytes than the known fields consumed.
+    TrailingBytes { extra: usize },
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::UnsupportedVersion(version) => {
+                write!(
+                    f,
+                    "unsupported Chip8State version {version}, expected {VERSION}"
+                )
+            }
+            DecodeError::UnexpectedEnd => write!(f, "byte slice ended before all fields were read"),
+            DecodeError::TrailingBytes { extra } => write!(f, "{extra} unread trailing byte(s)"),
+        }
+    }
+}
+
+impl error::Error for DecodeError {}
+
+#[cfg(test)]
+mod tests {
+    use super::super::Chip8;
+    use super::{Chip8State, DecodeError};
+
+    fn sample_chip8() -> Chip8 {
+        let mut chip8 = Chip8::new();
+        chip8.load_rom_bytes(&[0x60, 0x2A, 0x61, 0x7F]).unwrap();
+        chip8.step();
+        chip8.step();
+        chip8
+    }
+
+    #[test]
+    fn to_bytes_from_bytes_round_trips() {
+        let state = sample_chip8().snapshot();
+
+        let decoded = Chip8State::from_bytes(&state.to_bytes()).unwrap();
+
+        assert_eq!(state, decoded);
+    }
+
+    #[test]
+    fn restore_applies_a_round_tripped_snapshot() {
+        let original = sample_chip8();
+        let bytes = original.snapshot().to_bytes();
+
+        let mut fresh = Chip8::new();
+        fresh.restore(&Chip8State::from_bytes(&bytes).unwrap());
+
+        assert_eq!(fresh.peek_registers(), original.peek_registers());
+    }
+
+    #[test]
+    fn from_bytes_rejects_an_unsupported_version() {
+        let mut bytes = sample_chip8().snapshot().to_bytes();
+        bytes[0] = 0xFF;
+
+        assert_eq!(
+            Chip8State::from_bytes(&bytes),
+            Err(DecodeError::UnsupportedVersion(0xFF))
+        );
+    }
+
+    #[test]
+    fn from_bytes_rejects_a_truncated_buffer() {
+        let bytes = sample_chip8().snapshot().to_bytes();
+
+        assert_eq!(
+            Chip8State::from_bytes(&bytes[..bytes.len() - 1]),
+            Err(DecodeError::UnexpectedEnd)
+        );
+    }
+
+    #[test]
+    fn from_bytes_rejects_trailing_bytes() {
+        let mut bytes = sample_chip8().snapshot().to_bytes();
+        bytes.push(0x00);
+
+        assert_eq!(
+            Chip8State::from_bytes(&bytes),
+            Err(DecodeError::TrailingBytes { extra: 1 })
+        );
+    }
+}